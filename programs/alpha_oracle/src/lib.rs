@@ -2,23 +2,90 @@ use anchor_lang::prelude::*;
 
 declare_id!("BkQs8LxquVLUXHq44nQwpaenQzyZMBksrpVz2YN28MjV");
 
+/// Micro-unit convention: all prices on this program are expressed with 6
+/// decimals (e.g. `$1.25` == `1_250_000`). Price feeds publish with their own
+/// exponent, so feed quotes are rescaled to this convention before use.
+const PRICE_DECIMALS: i32 = 6;
+
 #[program]
 pub mod alpha_oracle {
     use super::*;
 
     /// Initialize a new oracle (one-time setup)
-    pub fn initialize_oracle(ctx: Context<InitializeOracle>, name: String) -> Result<()> {
+    pub fn initialize_oracle(
+        ctx: Context<InitializeOracle>,
+        name: String,
+        max_staleness_secs: i64,
+        max_confidence_bps: u64,
+        min_submissions: u8,
+        decay_num: u64,
+        decay_den: u64,
+        authorized_verifiers: Vec<Pubkey>,
+    ) -> Result<()> {
+        require!(
+            decay_den > 0 && decay_num <= decay_den,
+            AlphaOracleError::InvalidDecay
+        );
+        require!(
+            authorized_verifiers.len() <= Oracle::MAX_AUTHORIZED_VERIFIERS,
+            AlphaOracleError::TooManyVerifiers
+        );
         let oracle = &mut ctx.accounts.oracle;
+        oracle.authorized_verifiers = authorized_verifiers;
         oracle.authority = ctx.accounts.authority.key();
         oracle.name = name;
         oracle.total_predictions = 0;
         oracle.wins = 0;
         oracle.losses = 0;
-        oracle.created_at = Clock::get()?.unix_timestamp;
+        oracle.max_staleness_secs = max_staleness_secs;
+        oracle.max_confidence_bps = max_confidence_bps;
+        oracle.min_submissions = min_submissions;
+        oracle.decay_num = decay_num;
+        oracle.decay_den = decay_den;
+        oracle.score = 0;
+        oracle.win_rate_bps = 0;
+        oracle.streak = 0;
+        let now = Clock::get()?.unix_timestamp;
+        oracle.last_scored_at = now;
+        oracle.created_at = now;
         oracle.bump = ctx.bumps.oracle;
         Ok(())
     }
 
+    /// Replace the oracle's authorized-verifier allowlist. Only the oracle
+    /// authority may call this; submissions are accepted solely from keys on
+    /// this list, so the median can't be captured by spun-up keypairs.
+    pub fn set_authorized_verifiers(
+        ctx: Context<SetAuthorizedVerifiers>,
+        authorized_verifiers: Vec<Pubkey>,
+    ) -> Result<()> {
+        require!(
+            authorized_verifiers.len() <= Oracle::MAX_AUTHORIZED_VERIFIERS,
+            AlphaOracleError::TooManyVerifiers
+        );
+        ctx.accounts.oracle.authorized_verifiers = authorized_verifiers;
+        Ok(())
+    }
+
+    /// Publish (or overwrite) a price feed account. In production this stands in
+    /// for a Pyth/Switchboard feed; the keeper pushes the latest attested quote.
+    pub fn publish_price(
+        ctx: Context<PublishPrice>,
+        price: i64,
+        conf: u64,
+        expo: i32,
+    ) -> Result<()> {
+        let feed = &mut ctx.accounts.price_feed;
+        let clock = Clock::get()?;
+        feed.price = price;
+        feed.conf = conf;
+        feed.expo = expo;
+        feed.publish_ts = clock.unix_timestamp;
+        feed.slot = clock.slot;
+        feed.bump = ctx.bumps.price_feed;
+        Ok(())
+    }
+
     /// Create a new prediction
     pub fn create_prediction(
         ctx: Context<CreatePrediction>,
@@ -28,11 +95,17 @@ pub mod alpha_oracle {
         take_profit: u64,
         stop_loss: u64,
         timeframe_hours: u16,
+        fallback_feeds: Vec<Pubkey>,
     ) -> Result<()> {
         let oracle = &mut ctx.accounts.oracle;
         let prediction = &mut ctx.accounts.prediction;
         let clock = Clock::get()?;
 
+        require!(
+            fallback_feeds.len() <= Prediction::MAX_FALLBACK_FEEDS,
+            AlphaOracleError::TooManyFallbacks
+        );
+
         prediction.oracle = oracle.key();
         prediction.prediction_id = oracle.total_predictions;
         prediction.asset = asset;
@@ -43,7 +116,14 @@ pub mod alpha_oracle {
         prediction.created_at = clock.unix_timestamp;
         prediction.expires_at = clock.unix_timestamp + (timeframe_hours as i64 * 3600);
         prediction.status = PredictionStatus::Active;
+        prediction.price_feed = ctx.accounts.price_feed.key();
+        prediction.fallback_feeds = fallback_feeds;
+        prediction.max_price_seen = entry_price;
+        prediction.min_price_seen = entry_price;
+        prediction.max_seen_at = clock.unix_timestamp;
+        prediction.min_seen_at = clock.unix_timestamp;
         prediction.result_price = 0;
+        prediction.settled_event = PriceEventId::default();
         prediction.verified_at = 0;
         prediction.bump = ctx.bumps.prediction;
 
@@ -63,13 +143,56 @@ pub mod alpha_oracle {
         Ok(())
     }
 
-    /// Verify a prediction result (can be called by anyone after expiry)
-    pub fn verify_prediction(
-        ctx: Context<VerifyPrediction>,
-        result_price: u64,
-    ) -> Result<()> {
+    /// Record the running high and low seen on the bound price feed. Anyone may
+    /// call this during the active window; because on-chain code cannot observe
+    /// every tick, settlement relies on these samples to decide whether
+    /// take-profit or stop-loss was reached first.
+    pub fn checkpoint_prediction(ctx: Context<CheckpointPrediction>) -> Result<()> {
+        let oracle = &ctx.accounts.oracle;
+        let prediction = &mut ctx.accounts.prediction;
+        let feed = &ctx.accounts.price_feed;
+        let clock = Clock::get()?;
+
+        require!(
+            prediction.status == PredictionStatus::Active,
+            AlphaOracleError::PredictionNotActive
+        );
+        require!(
+            clock.unix_timestamp < prediction.expires_at,
+            AlphaOracleError::PredictionNotActive
+        );
+        require_keys_eq!(
+            feed.key(),
+            prediction.price_feed,
+            AlphaOracleError::WrongPriceFeed
+        );
+
+        // Only fresh, tight-enough samples may move the recorded extremes, so a
+        // stale or wide-band push can't plant a false high/low that later
+        // decides TP/SL-first classification.
+        let price = try_feed(feed, oracle, clock.unix_timestamp)?;
+        if price > prediction.max_price_seen {
+            prediction.max_price_seen = price;
+            prediction.max_seen_at = clock.unix_timestamp;
+        }
+        if price < prediction.min_price_seen {
+            prediction.min_price_seen = price;
+            prediction.min_seen_at = clock.unix_timestamp;
+        }
+
+        Ok(())
+    }
+
+    /// Verify a prediction result (can be called by anyone after expiry).
+    ///
+    /// The settlement price is read from the bound `price_feed` account (or,
+    /// when that feed is stale or too uncertain, from one of the prediction's
+    /// fallback feeds) and rescaled to the 6-decimal micro-unit convention, so
+    /// no caller can settle an outcome off a fabricated number.
+    pub fn verify_prediction(ctx: Context<VerifyPrediction>) -> Result<()> {
         let oracle = &mut ctx.accounts.oracle;
         let prediction = &mut ctx.accounts.prediction;
+        let feed = &ctx.accounts.price_feed;
         let clock = Clock::get()?;
 
         require!(
@@ -80,36 +203,142 @@ pub mod alpha_oracle {
             clock.unix_timestamp >= prediction.expires_at,
             AlphaOracleError::PredictionNotExpired
         );
+        require_keys_eq!(
+            feed.key(),
+            prediction.price_feed,
+            AlphaOracleError::WrongPriceFeed
+        );
 
-        prediction.result_price = result_price;
-        prediction.verified_at = clock.unix_timestamp;
-
-        // Determine win/loss based on direction and price movement
-        let is_win = match prediction.direction {
-            Direction::Long => {
-                // Win if price >= take_profit OR (price > entry AND not hit stop_loss)
-                result_price >= prediction.take_profit
-                    || (result_price > prediction.entry_price && result_price > prediction.stop_loss)
-            }
-            Direction::Short => {
-                // Win if price <= take_profit OR (price < entry AND not hit stop_loss)
-                result_price <= prediction.take_profit
-                    || (result_price < prediction.entry_price && result_price < prediction.stop_loss)
+        // Prefer the primary feed; if it is stale or too uncertain, hand off to
+        // the first of the prediction's fallback feeds, in their stored order,
+        // that is passed via `remaining_accounts` and passes the same checks.
+        let now = clock.unix_timestamp;
+        let (event, result_price, conf) = match try_feed(feed, oracle, now) {
+            Ok(price) => (PriceEventId::of(feed), price, feed.conf),
+            // Primary feed failed; remember why so we can surface a precise error
+            // if no fallback resolves either.
+            Err(primary_err) => {
+                let mut chosen = None;
+                for key in prediction.fallback_feeds.iter() {
+                    let Some(account) = ctx.remaining_accounts.iter().find(|a| a.key == key)
+                    else {
+                        continue;
+                    };
+                    let fallback: Account<PriceFeed> = Account::try_from(account)?;
+                    if let Ok(price) = try_feed(&fallback, oracle, now) {
+                        chosen = Some((PriceEventId::of(&fallback), price, fallback.conf));
+                        break;
+                    }
+                }
+                match chosen {
+                    Some(resolved) => resolved,
+                    // No fallback configured → report the primary's exact fault
+                    // (stale / wide-band / invalid); fallbacks tried but all
+                    // failed → the generic no-valid-feed error.
+                    None if prediction.fallback_feeds.is_empty() => return Err(primary_err.into()),
+                    None => return Err(AlphaOracleError::NoValidFeed.into()),
+                }
             }
         };
 
-        if is_win {
-            prediction.status = PredictionStatus::Won;
-            oracle.wins += 1;
-        } else {
-            prediction.status = PredictionStatus::Lost;
-            oracle.losses += 1;
-        }
+        prediction.settled_event = event.clone();
+        let oracle_key = oracle.key();
+        settle_prediction(oracle, oracle_key, prediction, result_price, now);
+
+        emit!(PredictionVerified {
+            oracle: oracle.key(),
+            prediction_id: prediction.prediction_id,
+            result_price,
+            conf,
+            settling_feed: event.feed,
+            price_event: event,
+            status: prediction.status.clone(),
+        });
+
+        Ok(())
+    }
+
+    /// Submit one verifier's observed price into the prediction's verification
+    /// round. Only a key on the oracle's authorized-verifier allowlist may
+    /// submit, and each may submit exactly one value after expiry; the round
+    /// collects independent quotes so no single verifier controls the
+    /// settlement. Resolution happens separately in `resolve_prediction`.
+    pub fn submit_verification(ctx: Context<SubmitVerification>, value: u64) -> Result<()> {
+        let oracle = &ctx.accounts.oracle;
+        let prediction = &ctx.accounts.prediction;
+        let round = &mut ctx.accounts.round;
+        let clock = Clock::get()?;
+
+        require!(
+            prediction.status == PredictionStatus::Active && !round.resolved,
+            AlphaOracleError::PredictionNotActive
+        );
+        require!(
+            clock.unix_timestamp >= prediction.expires_at,
+            AlphaOracleError::PredictionNotExpired
+        );
+
+        let verifier = ctx.accounts.verifier.key();
+        require!(
+            oracle.authorized_verifiers.contains(&verifier),
+            AlphaOracleError::UnauthorizedVerifier
+        );
+
+        round.prediction = prediction.key();
+        round.bump = ctx.bumps.round;
+        require!(
+            !round.submissions.iter().any(|s| s.verifier == verifier),
+            AlphaOracleError::DuplicateVerifier
+        );
+        require!(
+            round.submissions.len() < VerificationRound::MAX_SUBMISSIONS,
+            AlphaOracleError::RoundFull
+        );
+        round.submissions.push(Submission { verifier, value });
+
+        Ok(())
+    }
+
+    /// Resolve a prediction off the median of its collected submissions. Callable
+    /// once `min_submissions` distinct quotes have been recorded; sorts the
+    /// values, settles the outcome off the central one, and marks the round
+    /// resolved so late submissions are rejected.
+    pub fn resolve_prediction(ctx: Context<ResolvePrediction>) -> Result<()> {
+        let oracle = &mut ctx.accounts.oracle;
+        let prediction = &mut ctx.accounts.prediction;
+        let round = &mut ctx.accounts.round;
+        let clock = Clock::get()?;
+
+        require!(
+            prediction.status == PredictionStatus::Active && !round.resolved,
+            AlphaOracleError::PredictionNotActive
+        );
+        require!(
+            round.submissions.len() >= oracle.min_submissions as usize,
+            AlphaOracleError::NotEnoughSubmissions
+        );
+
+        let mut values: Vec<u64> = round.submissions.iter().map(|s| s.value).collect();
+        values.sort_unstable();
+        let result_price = median(&values);
+
+        round.resolved = true;
+        let event = PriceEventId {
+            feed: prediction.price_feed,
+            slot: clock.slot,
+            publish_ts: clock.unix_timestamp,
+        };
+        prediction.settled_event = event.clone();
+        let oracle_key = oracle.key();
+        settle_prediction(oracle, oracle_key, prediction, result_price, clock.unix_timestamp);
 
         emit!(PredictionVerified {
             oracle: oracle.key(),
             prediction_id: prediction.prediction_id,
             result_price,
+            conf: 0,
+            settling_feed: event.feed,
+            price_event: event,
             status: prediction.status.clone(),
         });
 
@@ -117,6 +346,186 @@ pub mod alpha_oracle {
     }
 }
 
+/// Record a settlement price on `prediction`, classify it win/loss by direction
+/// and price movement, and roll the outcome into the oracle's tally. Shared by
+/// the single-feed (`verify_prediction`) and median (`resolve_prediction`)
+/// settlement paths.
+fn settle_prediction(
+    oracle: &mut Oracle,
+    oracle_key: Pubkey,
+    prediction: &mut Prediction,
+    result_price: u64,
+    now: i64,
+) {
+    prediction.result_price = result_price;
+    prediction.verified_at = now;
+
+    // Classify off the intra-period path: if only one of take-profit /
+    // stop-loss was reached by the recorded extremes, it decides the outcome;
+    // if both were, the extreme with the earlier timestamp wins. Each
+    // timestamp is that of the checkpoint that last moved the extreme, not of
+    // the first touch of the level. If neither level was ever recorded, fall
+    // back to the final-price comparison.
+    let is_win = match prediction.direction {
+        Direction::Long => {
+            let tp_hit = prediction.max_price_seen >= prediction.take_profit;
+            let sl_hit = prediction.min_price_seen <= prediction.stop_loss;
+            match (tp_hit, sl_hit) {
+                (true, true) => prediction.max_seen_at <= prediction.min_seen_at,
+                (true, false) => true,
+                (false, true) => false,
+                (false, false) => {
+                    result_price > prediction.entry_price && result_price > prediction.stop_loss
+                }
+            }
+        }
+        Direction::Short => {
+            let tp_hit = prediction.min_price_seen <= prediction.take_profit;
+            let sl_hit = prediction.max_price_seen >= prediction.stop_loss;
+            match (tp_hit, sl_hit) {
+                (true, true) => prediction.min_seen_at <= prediction.max_seen_at,
+                (true, false) => true,
+                (false, true) => false,
+                (false, false) => {
+                    result_price < prediction.entry_price && result_price < prediction.stop_loss
+                }
+            }
+        }
+    };
+
+    if is_win {
+        prediction.status = PredictionStatus::Won;
+        oracle.wins += 1;
+    } else {
+        prediction.status = PredictionStatus::Lost;
+        oracle.losses += 1;
+    }
+
+    update_reputation(oracle, oracle_key, prediction, is_win, now);
+}
+
+/// Base reputation weight earned for a correct call, before the overshoot bonus.
+const REPUTATION_BASE_WEIGHT: i64 = 100;
+/// Cap on the overshoot bonus (in basis points of take-profit) so a single
+/// outsized move can't dominate the score.
+const MAX_OVERSHOOT_BONUS_BPS: i64 = 10_000;
+
+/// Fold a settled outcome into the oracle's reputation: decay the existing
+/// score toward neutral, add a weight for a win that scales with how far the
+/// move ran past take-profit (or subtract the flat base weight for a loss),
+/// and refresh the rolling win-rate and streak.
+fn update_reputation(
+    oracle: &mut Oracle,
+    oracle_key: Pubkey,
+    prediction: &Prediction,
+    is_win: bool,
+    now: i64,
+) {
+    // The overshoot bonus rewards how far a winning call ran past take-profit.
+    // Losses carry only the flat base weight: a losing trade that happened to
+    // spike past take-profit after stopping out didn't realize that move, so it
+    // must not be penalized for it.
+    let delta = if is_win {
+        let reached = match prediction.direction {
+            Direction::Long => prediction.max_price_seen,
+            Direction::Short => prediction.min_price_seen,
+        };
+        let overshoot_bps = if prediction.take_profit > 0 {
+            let diff = match prediction.direction {
+                Direction::Long => reached.saturating_sub(prediction.take_profit),
+                Direction::Short => prediction.take_profit.saturating_sub(reached),
+            };
+            ((diff as u128 * 10_000) / prediction.take_profit as u128) as i64
+        } else {
+            0
+        };
+        REPUTATION_BASE_WEIGHT + overshoot_bps.min(MAX_OVERSHOOT_BONUS_BPS)
+    } else {
+        -REPUTATION_BASE_WEIGHT
+    };
+
+    oracle.score = (oracle.score as i128 * oracle.decay_num as i128 / oracle.decay_den as i128
+        + delta as i128) as i64;
+    oracle.last_scored_at = now;
+
+    let settled = oracle.wins + oracle.losses;
+    oracle.win_rate_bps = if settled > 0 {
+        ((oracle.wins as u128 * 10_000) / settled as u128) as u16
+    } else {
+        0
+    };
+
+    oracle.streak = if is_win {
+        if oracle.streak > 0 {
+            oracle.streak + 1
+        } else {
+            1
+        }
+    } else if oracle.streak < 0 {
+        oracle.streak - 1
+    } else {
+        -1
+    };
+
+    emit!(ReputationUpdated {
+        oracle: oracle_key,
+        score: oracle.score,
+        win_rate_bps: oracle.win_rate_bps,
+        streak: oracle.streak,
+    });
+}
+
+/// Evaluate a feed against the oracle's staleness and confidence policy. Returns
+/// the settlement price in micro-units when the quote is fresh and tight enough
+/// to settle off, otherwise the specific reason it was rejected so callers can
+/// either fall back to another feed or surface a diagnosable error.
+fn try_feed(feed: &PriceFeed, oracle: &Oracle, now: i64) -> core::result::Result<u64, AlphaOracleError> {
+    if feed.price <= 0 {
+        return Err(AlphaOracleError::InvalidPrice);
+    }
+    if now - feed.publish_ts > oracle.max_staleness_secs {
+        return Err(AlphaOracleError::OracleStale);
+    }
+    if (feed.conf as u128) * 10_000 / (feed.price as u128) > oracle.max_confidence_bps as u128 {
+        return Err(AlphaOracleError::OracleConfidence);
+    }
+    scale_to_micro(feed.price, feed.expo).map_err(|_| AlphaOracleError::InvalidPrice)
+}
+
+/// Median of a set of submitted values. For an even count, the average of the
+/// two central entries (flooring). `values` is sorted in place by the caller.
+fn median(values: &[u64]) -> u64 {
+    let n = values.len();
+    if n % 2 == 1 {
+        values[n / 2]
+    } else {
+        let lo = values[n / 2 - 1] as u128;
+        let hi = values[n / 2] as u128;
+        ((lo + hi) / 2) as u64
+    }
+}
+
+/// Rescale a feed quote published with exponent `expo` into the program's
+/// 6-decimal micro-unit convention. Pyth-style exponents are typically
+/// negative (e.g. `-8`), so this usually divides the raw mantissa down.
+fn scale_to_micro(price: i64, expo: i32) -> Result<u64> {
+    require!(price > 0, AlphaOracleError::InvalidPrice);
+    let mut value = price as u128;
+    let shift = expo + PRICE_DECIMALS;
+    if shift >= 0 {
+        let factor = 10u128
+            .checked_pow(shift as u32)
+            .ok_or(AlphaOracleError::InvalidPrice)?;
+        value = value.checked_mul(factor).ok_or(AlphaOracleError::InvalidPrice)?;
+    } else {
+        let factor = 10u128
+            .checked_pow((-shift) as u32)
+            .ok_or(AlphaOracleError::InvalidPrice)?;
+        value /= factor;
+    }
+    u64::try_from(value).map_err(|_| AlphaOracleError::InvalidPrice.into())
+}
+
 // === ACCOUNTS ===
 
 #[derive(Accounts)]
@@ -135,6 +544,33 @@ pub struct InitializeOracle<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct SetAuthorizedVerifiers<'info> {
+    #[account(
+        mut,
+        seeds = [b"oracle", authority.key().as_ref()],
+        bump = oracle.bump,
+        has_one = authority
+    )]
+    pub oracle: Account<'info, Oracle>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PublishPrice<'info> {
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        space = 8 + PriceFeed::INIT_SPACE,
+        seeds = [b"price_feed", keeper.key().as_ref()],
+        bump
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct CreatePrediction<'info> {
     #[account(
@@ -151,11 +587,33 @@ pub struct CreatePrediction<'info> {
         bump
     )]
     pub prediction: Account<'info, Prediction>,
+    /// Price feed this prediction binds to; its pubkey is recorded so
+    /// verification can only settle off this exact source.
+    pub price_feed: Account<'info, PriceFeed>,
     #[account(mut)]
     pub authority: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct CheckpointPrediction<'info> {
+    #[account(
+        seeds = [b"oracle", oracle.authority.as_ref()],
+        bump = oracle.bump
+    )]
+    pub oracle: Account<'info, Oracle>,
+    #[account(
+        mut,
+        seeds = [b"prediction", oracle.key().as_ref(), &prediction.prediction_id.to_le_bytes()],
+        bump = prediction.bump,
+        constraint = prediction.oracle == oracle.key()
+    )]
+    pub prediction: Account<'info, Prediction>,
+    /// Must match the feed the prediction was bound to at creation.
+    pub price_feed: Account<'info, PriceFeed>,
+    pub keeper: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct VerifyPrediction<'info> {
     #[account(
@@ -171,9 +629,62 @@ pub struct VerifyPrediction<'info> {
         constraint = prediction.oracle == oracle.key()
     )]
     pub prediction: Account<'info, Prediction>,
+    /// Must match the feed the prediction was bound to at creation.
+    pub price_feed: Account<'info, PriceFeed>,
     pub verifier: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct SubmitVerification<'info> {
+    #[account(
+        seeds = [b"oracle", oracle.authority.as_ref()],
+        bump = oracle.bump
+    )]
+    pub oracle: Account<'info, Oracle>,
+    #[account(
+        seeds = [b"prediction", oracle.key().as_ref(), &prediction.prediction_id.to_le_bytes()],
+        bump = prediction.bump,
+        constraint = prediction.oracle == oracle.key()
+    )]
+    pub prediction: Account<'info, Prediction>,
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        space = 8 + VerificationRound::INIT_SPACE,
+        seeds = [b"round", prediction.key().as_ref()],
+        bump
+    )]
+    pub round: Account<'info, VerificationRound>,
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResolvePrediction<'info> {
+    #[account(
+        mut,
+        seeds = [b"oracle", oracle.authority.as_ref()],
+        bump = oracle.bump
+    )]
+    pub oracle: Account<'info, Oracle>,
+    #[account(
+        mut,
+        seeds = [b"prediction", oracle.key().as_ref(), &prediction.prediction_id.to_le_bytes()],
+        bump = prediction.bump,
+        constraint = prediction.oracle == oracle.key()
+    )]
+    pub prediction: Account<'info, Prediction>,
+    #[account(
+        mut,
+        seeds = [b"round", prediction.key().as_ref()],
+        bump = round.bump,
+        constraint = round.prediction == prediction.key()
+    )]
+    pub round: Account<'info, VerificationRound>,
+    pub resolver: Signer<'info>,
+}
+
 // === STATE ===
 
 #[account]
@@ -185,10 +696,36 @@ pub struct Oracle {
     pub total_predictions: u64,
     pub wins: u64,
     pub losses: u64,
+    /// Maximum age, in seconds, a feed quote may have to settle a prediction.
+    pub max_staleness_secs: i64,
+    /// Maximum accepted confidence band, in basis points of the quote price.
+    pub max_confidence_bps: u64,
+    /// Distinct submissions required before a prediction can be resolved by median.
+    pub min_submissions: u8,
+    /// Keys permitted to submit into a verification round; an empty set disables
+    /// the submission path entirely.
+    #[max_len(16)]
+    pub authorized_verifiers: Vec<Pubkey>,
+    /// Geometric decay applied to `score` on each settlement (`score * num / den`).
+    pub decay_num: u64,
+    pub decay_den: u64,
+    /// Time-decayed "alpha" score, signed; drifts toward neutral as it decays.
+    pub score: i64,
+    /// Rolling win-rate across all settled predictions, in basis points.
+    pub win_rate_bps: u16,
+    /// Current streak: positive for consecutive wins, negative for losses.
+    pub streak: i32,
+    /// Timestamp of the most recent score update.
+    pub last_scored_at: i64,
     pub created_at: i64,
     pub bump: u8,
 }
 
+impl Oracle {
+    /// Matches the `#[max_len]` cap on `authorized_verifiers`.
+    pub const MAX_AUTHORIZED_VERIFIERS: usize = 16;
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct Prediction {
@@ -203,13 +740,134 @@ pub struct Prediction {
     pub created_at: i64,
     pub expires_at: i64,
     pub status: PredictionStatus,
+    /// Primary feed this prediction is settled against.
+    pub price_feed: Pubkey,
+    /// Ordered backup feeds tried (via `remaining_accounts`) when the primary
+    /// is stale or too uncertain.
+    #[max_len(4)]
+    pub fallback_feeds: Vec<Pubkey>,
+    /// Running extremes observed on the bound feed during the active window,
+    /// with the timestamp each extreme was recorded.
+    pub max_price_seen: u64,
+    pub min_price_seen: u64,
+    pub max_seen_at: i64,
+    pub min_seen_at: i64,
     pub result_price: u64,
+    /// Canonical reference to the price observation that settled this
+    /// prediction; default until verification. A median resolution records
+    /// the bound feed with the slot and time of resolution.
+    pub settled_event: PriceEventId,
     pub verified_at: i64,
     pub bump: u8,
 }
 
+impl Prediction {
+    /// Matches the `#[max_len]` cap on `fallback_feeds`.
+    pub const MAX_FALLBACK_FEEDS: usize = 4;
+}
+
+/// Latest attested quote from a price source, pushed by a keeper. Mirrors the
+/// shape of a Pyth/Switchboard feed: a signed mantissa, a confidence band, and
+/// the exponent the pair is scaled by, plus the publication slot and time.
+#[account]
+#[derive(InitSpace)]
+pub struct PriceFeed {
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub publish_ts: i64,
+    pub slot: u64,
+    pub bump: u8,
+}
+
+/// Collects independent verifier submissions for one prediction so it can be
+/// resolved off their median rather than a single verifier's word.
+#[account]
+#[derive(InitSpace)]
+pub struct VerificationRound {
+    pub prediction: Pubkey,
+    #[max_len(16)]
+    pub submissions: Vec<Submission>,
+    pub resolved: bool,
+    pub bump: u8,
+}
+
+impl VerificationRound {
+    /// Matches the `#[max_len]` cap on `submissions`.
+    pub const MAX_SUBMISSIONS: usize = 16;
+}
+
 // === TYPES ===
 
+/// Canonical reference to the exact price observation that settled a prediction:
+/// the feed it came from, the slot it was read at, and its publish timestamp.
+/// Pins a settlement to an identifiable event so clients can deterministically
+/// re-fetch and audit it. Encodes as `<feed>:<slot>:<publish_ts>` (feed in
+/// base58) via `Display`, and round-trips back through `FromStr`.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq, Debug, InitSpace,
+)]
+pub struct PriceEventId {
+    pub feed: Pubkey,
+    pub slot: u64,
+    pub publish_ts: i64,
+}
+
+impl PriceEventId {
+    /// Capture the event id of a feed account's current quote.
+    pub fn of(feed: &Account<PriceFeed>) -> Self {
+        Self {
+            feed: feed.key(),
+            slot: feed.slot,
+            publish_ts: feed.publish_ts,
+        }
+    }
+}
+
+impl core::fmt::Display for PriceEventId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}:{}:{}", self.feed, self.slot, self.publish_ts)
+    }
+}
+
+impl core::str::FromStr for PriceEventId {
+    type Err = PriceEventIdParseError;
+
+    fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+        let mut parts = s.split(':');
+        let feed = parts
+            .next()
+            .and_then(|p| p.parse::<Pubkey>().ok())
+            .ok_or(PriceEventIdParseError)?;
+        let slot = parts
+            .next()
+            .and_then(|p| p.parse::<u64>().ok())
+            .ok_or(PriceEventIdParseError)?;
+        let publish_ts = parts
+            .next()
+            .and_then(|p| p.parse::<i64>().ok())
+            .ok_or(PriceEventIdParseError)?;
+        if parts.next().is_some() {
+            return Err(PriceEventIdParseError);
+        }
+        Ok(Self {
+            feed,
+            slot,
+            publish_ts,
+        })
+    }
+}
+
+/// Returned when a `PriceEventId` string is not in canonical form.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PriceEventIdParseError;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Submission {
+    pub verifier: Pubkey,
+    pub value: u64,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
 pub enum Direction {
     Long,
@@ -243,9 +901,25 @@ pub struct PredictionVerified {
     pub oracle: Pubkey,
     pub prediction_id: u64,
     pub result_price: u64,
+    pub conf: u64,
+    /// Feed whose quote settled the prediction (primary or a fallback). For a
+    /// prediction resolved off the median of submissions, the bound primary
+    /// feed, whose quote was not read.
+    pub settling_feed: Pubkey,
+    /// Canonical reference to the exact settling price observation. For a
+    /// median resolution, the bound feed with the slot and time of resolution.
+    pub price_event: PriceEventId,
     pub status: PredictionStatus,
 }
 
+#[event]
+pub struct ReputationUpdated {
+    pub oracle: Pubkey,
+    pub score: i64,
+    pub win_rate_bps: u16,
+    pub streak: i32,
+}
+
 // === ERRORS ===
 
 #[error_code]
@@ -254,4 +928,28 @@ pub enum AlphaOracleError {
     PredictionNotActive,
     #[msg("Prediction has not expired yet")]
     PredictionNotExpired,
+    #[msg("Price feed does not match the one bound to this prediction")]
+    WrongPriceFeed,
+    #[msg("Price feed quote is too stale to settle this prediction")]
+    OracleStale,
+    #[msg("Price feed confidence band is too wide to settle this prediction")]
+    OracleConfidence,
+    #[msg("This verifier is not on the oracle's authorized set")]
+    UnauthorizedVerifier,
+    #[msg("Too many authorized verifiers for this oracle")]
+    TooManyVerifiers,
+    #[msg("This verifier has already submitted to the round")]
+    DuplicateVerifier,
+    #[msg("The verification round is full")]
+    RoundFull,
+    #[msg("Not enough submissions to resolve this prediction")]
+    NotEnoughSubmissions,
+    #[msg("Too many fallback feeds for this prediction")]
+    TooManyFallbacks,
+    #[msg("No primary or fallback feed passed the staleness and confidence checks")]
+    NoValidFeed,
+    #[msg("Decay ratio must have a non-zero denominator and num <= den")]
+    InvalidDecay,
+    #[msg("Price feed quote is invalid")]
+    InvalidPrice,
 }